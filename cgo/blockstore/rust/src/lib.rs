@@ -1,4 +1,5 @@
 use cid::Cid;
+use std::convert::TryInto;
 use std::ptr;
 
 use core::fmt;
@@ -8,14 +9,43 @@ use blockstore;
 
 pub mod sys;
 
-const ERR_NO_STORE: i32 = -1;
-const ERR_NOT_FOUND: i32 = -2;
+use sys::{
+    ERR_IO, ERR_NOT_FOUND, ERR_NO_STORE, ERR_SERIALIZATION, ERR_SIZE_LIMIT_EXCEEDED,
+    ERR_STORE_UNAVAILABLE,
+};
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum Error {
+    /// The requested key was not present in the store.
     NotFound,
-    Other,
+    /// The Go side failed to (de)serialize a key or block. Carries the raw
+    /// return code.
+    Serialization(i32),
+    /// The backing store returned an I/O error. Carries the raw return code.
+    Io(i32),
+    /// The backing store is temporarily unavailable; the caller may retry.
+    /// Carries the raw return code.
+    StoreUnavailable(i32),
+    /// The request exceeded a size limit enforced by the Go side. Carries the
+    /// raw return code.
+    SizeLimitExceeded(i32),
+    /// An FFI return code we don't have a specific variant for. Carries the
+    /// raw return code.
+    Other(i32),
+}
+
+/// Maps an FFI return code that isn't a known success value into a structured
+/// [`Error`], preserving the raw code for unrecognized values.
+fn error_from_code(code: i32) -> Error {
+    match code {
+        ERR_NOT_FOUND => Error::NotFound,
+        ERR_SERIALIZATION => Error::Serialization(code),
+        ERR_IO => Error::Io(code),
+        ERR_STORE_UNAVAILABLE => Error::StoreUnavailable(code),
+        ERR_SIZE_LIMIT_EXCEEDED => Error::SizeLimitExceeded(code),
+        _ => Error::Other(code),
+    }
 }
 
 pub struct Blockstore {
@@ -27,6 +57,131 @@ impl Blockstore {
     pub unsafe fn new(handle: i32) -> Blockstore {
         Blockstore { handle }
     }
+
+    /// Batched variant of [`has`](blockstore::Blockstore::has) for many keys at
+    /// once. Marshals all the keys across the cgo boundary in a single call,
+    /// amortizing the per-call overhead over the batch. Returns one bool per
+    /// key, preserving input order.
+    pub fn has_many(&self, ks: &[Cid]) -> Result<Vec<bool>, Error> {
+        let packed = pack_keys(ks);
+        unsafe {
+            let mut buf: *mut u8 = ptr::null_mut();
+            let mut size: i32 = 0;
+            match sys::cgobs_has_many(
+                self.handle,
+                packed.as_ptr(),
+                packed.len() as i32,
+                ks.len() as i32,
+                &mut buf,
+                &mut size,
+            ) {
+                0 => {
+                    let raw = Vec::from_raw_parts(buf, size as usize, size as usize);
+                    Ok(raw.into_iter().map(|b| b != 0).collect())
+                }
+                ERR_NO_STORE => panic!("blockstore {} not registered", self.handle),
+                code => Err(error_from_code(code)),
+            }
+        }
+    }
+
+    /// Batched variant of [`get`](blockstore::Blockstore::get) for many keys at
+    /// once; see [`has_many`](Self::has_many) for the motivation. Returns one
+    /// optional block reader per key, preserving input order; a key not found
+    /// in the store yields `None` rather than an error.
+    pub fn get_many(&self, ks: &[Cid]) -> Result<Vec<Option<BlockReader>>, Error> {
+        let packed = pack_keys(ks);
+        unsafe {
+            let mut buf: *mut u8 = ptr::null_mut();
+            let mut size: i32 = 0;
+            match sys::cgobs_get_many(
+                self.handle,
+                packed.as_ptr(),
+                packed.len() as i32,
+                ks.len() as i32,
+                &mut buf,
+                &mut size,
+            ) {
+                0 => {
+                    let raw = Vec::from_raw_parts(buf, size as usize, size as usize);
+                    Ok(unpack_get_many_results(&raw, ks))
+                }
+                ERR_NO_STORE => panic!("blockstore {} not registered", self.handle),
+                code => Err(error_from_code(code)),
+            }
+        }
+    }
+
+    /// Batched variant of [`put`](blockstore::Blockstore::put) for many
+    /// key/block pairs at once; see [`has_many`](Self::has_many) for the
+    /// motivation.
+    pub fn put_many<'a>(&self, blocks: &[(Cid, &'a [u8])]) -> Result<(), Error> {
+        let packed = pack_entries(blocks);
+        unsafe {
+            match sys::cgobs_put_many(
+                self.handle,
+                packed.as_ptr(),
+                packed.len() as i32,
+                blocks.len() as i32,
+            ) {
+                0 => Ok(()),
+                ERR_NO_STORE => panic!("blockstore {} not registered", self.handle),
+                code => Err(error_from_code(code)),
+            }
+        }
+    }
+}
+
+/// Packs CIDs as a sequence of 4-byte LE length-prefixed byte strings, for
+/// marshalling across the cgo boundary in one shot.
+fn pack_keys(ks: &[Cid]) -> Vec<u8> {
+    let mut packed = Vec::new();
+    for k in ks {
+        let bytes = k.to_bytes();
+        packed.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        packed.extend_from_slice(&bytes);
+    }
+    packed
+}
+
+/// Packs key/block pairs as a sequence of (4-byte LE key length, key bytes,
+/// 4-byte LE block length, block bytes) records.
+fn pack_entries(entries: &[(Cid, &[u8])]) -> Vec<u8> {
+    let mut packed = Vec::new();
+    for (k, block) in entries {
+        let key_bytes = k.to_bytes();
+        packed.extend_from_slice(&(key_bytes.len() as u32).to_le_bytes());
+        packed.extend_from_slice(&key_bytes);
+        packed.extend_from_slice(&(block.len() as u32).to_le_bytes());
+        packed.extend_from_slice(block);
+    }
+    packed
+}
+
+/// Unpacks the result buffer from `cgobs_get_many`: `num_keys` records, each a
+/// 4-byte LE status (0 = found, [`ERR_NOT_FOUND`] = missing) followed, when
+/// found, by a 4-byte LE block length and the block bytes.
+fn unpack_get_many_results(raw: &[u8], ks: &[Cid]) -> Vec<Option<BlockReader>> {
+    let mut out = Vec::with_capacity(ks.len());
+    let mut pos = 0;
+    for k in ks {
+        let status = i32::from_le_bytes(raw[pos..pos + 4].try_into().unwrap());
+        pos += 4;
+        if status == ERR_NOT_FOUND {
+            out.push(None);
+            continue;
+        }
+        let len = u32::from_le_bytes(raw[pos..pos + 4].try_into().unwrap()) as usize;
+        pos += 4;
+        let block = raw[pos..pos + len].to_vec();
+        pos += len;
+        out.push(Some(BlockReader {
+            cid: *k,
+            reader: io::Cursor::new(block),
+            length: len,
+        }));
+    }
+    out
 }
 
 pub struct BlockReader {
@@ -54,7 +209,13 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::NotFound => f.write_str("not found"),
-            Self::Other => f.write_str("other"),
+            Self::Serialization(code) => write!(f, "serialization error (code {})", code),
+            Self::Io(code) => write!(f, "backing store I/O error (code {})", code),
+            Self::StoreUnavailable(code) => {
+                write!(f, "backing store temporarily unavailable (code {})", code)
+            }
+            Self::SizeLimitExceeded(code) => write!(f, "size limit exceeded (code {})", code),
+            Self::Other(code) => write!(f, "other error (code {})", code),
         }
     }
 }
@@ -80,8 +241,7 @@ impl blockstore::Blockstore for Blockstore {
                 // Panic if the store isn't registered. This means something _very_ unsafe is going
                 // on and there is a bug in the program.
                 ERR_NO_STORE => panic!("blockstore {} not registered", self.handle),
-                // Otherwise, return "other". We should add error codes in the future.
-                _ => Err(Error::Other),
+                code => Err(error_from_code(code)),
             }
         }
     }
@@ -106,7 +266,7 @@ impl blockstore::Blockstore for Blockstore {
                 r @ 1.. => panic!("invalid return value from has: {}", r),
                 ERR_NO_STORE => panic!("blockstore {} not registered", self.handle),
                 ERR_NOT_FOUND => Err(Error::NotFound),
-                _ => Err(Error::Other),
+                code => Err(error_from_code(code)),
             }
         }
     }
@@ -126,7 +286,7 @@ impl blockstore::Blockstore for Blockstore {
                 ERR_NO_STORE => panic!("blockstore {} not registered", self.handle),
                 // This error makes no sense.
                 ERR_NOT_FOUND => panic!("not found error on put"),
-                _ => Err(Error::Other),
+                code => Err(error_from_code(code)),
             }
         }
     }
@@ -140,7 +300,7 @@ impl blockstore::Blockstore for Blockstore {
                 ERR_NO_STORE => panic!("blockstore {} not registered", self.handle),
                 // We shouldn't get this... but it's not an issue.
                 ERR_NOT_FOUND => Ok(()),
-                _ => Err(Error::Other),
+                code => Err(error_from_code(code)),
             }
         }
     }
@@ -0,0 +1,69 @@
+//! Raw cgo entry points backing [`crate::Blockstore`]. These are implemented on
+//! the Go side and cross the cgo boundary, which carries a large constant
+//! overhead per call independent of payload size.
+
+/// No blockstore is registered under the given handle.
+pub const ERR_NO_STORE: i32 = -1;
+/// The requested key was not present in the store.
+pub const ERR_NOT_FOUND: i32 = -2;
+/// The Go side failed to (de)serialize a key or block.
+pub const ERR_SERIALIZATION: i32 = -3;
+/// The backing store returned an I/O error.
+pub const ERR_IO: i32 = -4;
+/// The backing store is temporarily unavailable; the caller may retry.
+pub const ERR_STORE_UNAVAILABLE: i32 = -5;
+/// The request exceeded a size limit enforced by the Go side.
+pub const ERR_SIZE_LIMIT_EXCEEDED: i32 = -6;
+
+extern "C" {
+    pub fn cgobs_get(
+        handle: i32,
+        cid: *const u8,
+        cid_len: i32,
+        obuf: *mut *mut u8,
+        obuf_len: *mut i32,
+    ) -> i32;
+
+    pub fn cgobs_has(handle: i32, cid: *const u8, cid_len: i32) -> i32;
+
+    pub fn cgobs_put(
+        handle: i32,
+        cid: *const u8,
+        cid_len: i32,
+        obuf: *const u8,
+        obuf_len: i32,
+    ) -> i32;
+
+    pub fn cgobs_delete(handle: i32, cid: *const u8, cid_len: i32) -> i32;
+
+    /// Batched [`cgobs_has`]. `keys` is `num_keys` length-prefixed (4-byte LE)
+    /// CID byte strings packed back to back. On success, `obuf` receives one
+    /// byte per key (0 or 1), in input order.
+    pub fn cgobs_has_many(
+        handle: i32,
+        keys: *const u8,
+        keys_len: i32,
+        num_keys: i32,
+        obuf: *mut *mut u8,
+        obuf_len: *mut i32,
+    ) -> i32;
+
+    /// Batched [`cgobs_get`]. `keys` is packed as in [`cgobs_has_many`]. On
+    /// success, `obuf` receives `num_keys` packed results, each a 4-byte LE
+    /// status (0 = found, [`crate::ERR_NOT_FOUND`] = missing) followed, when
+    /// found, by a 4-byte LE block length and the block bytes.
+    pub fn cgobs_get_many(
+        handle: i32,
+        keys: *const u8,
+        keys_len: i32,
+        num_keys: i32,
+        obuf: *mut *mut u8,
+        obuf_len: *mut i32,
+    ) -> i32;
+
+    /// Batched [`cgobs_put`]. `entries` is `num_entries` back-to-back records,
+    /// each a 4-byte LE key length, the key bytes, a 4-byte LE block length,
+    /// and the block bytes.
+    pub fn cgobs_put_many(handle: i32, entries: *const u8, entries_len: i32, num_entries: i32)
+        -> i32;
+}
@@ -0,0 +1,95 @@
+use fvm_shared::address::Address;
+use fvm_shared::consensus::ConsensusFault;
+use fvm_shared::crypto::signature::Signature;
+use std::fmt;
+use wasmtime::Trap;
+
+/// An error returned by a [`Kernel`] method. Syscall bindings convert these
+/// into a [`wasmtime::Trap`]; actor code never observes this type directly.
+#[derive(Debug)]
+pub enum ExecutionError {
+    /// A fatal, unrecoverable error; execution of the whole call stack must
+    /// stop.
+    Fatal(anyhow::Error),
+    /// A non-fatal error raised by the syscall itself. Like `Fatal`, it is
+    /// converted into a [`wasmtime::Trap`] by the syscall bindings.
+    Syscall(SyscallError),
+}
+
+/// The payload of [`ExecutionError::Syscall`]: a message describing why a
+/// syscall failed.
+#[derive(Debug)]
+pub struct SyscallError(pub String);
+
+impl fmt::Display for SyscallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SyscallError {}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionError::Fatal(e) => write!(f, "fatal error: {}", e),
+            ExecutionError::Syscall(e) => write!(f, "syscall error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ExecutionError {}
+
+impl From<anyhow::Error> for ExecutionError {
+    fn from(e: anyhow::Error) -> Self {
+        ExecutionError::Fatal(e)
+    }
+}
+
+impl From<SyscallError> for ExecutionError {
+    fn from(e: SyscallError) -> Self {
+        ExecutionError::Syscall(e)
+    }
+}
+
+impl From<ExecutionError> for Trap {
+    fn from(e: ExecutionError) -> Self {
+        Trap::new(e.to_string())
+    }
+}
+
+/// The capabilities every kernel implementation provides to actors, regardless
+/// of which network they're embedded in: signature verification, hashing,
+/// secp256k1 key recovery, and the low-level return-stack plumbing syscalls
+/// build on. Filecoin-specific proof verification lives behind the separate
+/// [`FilecoinKernel`](crate::syscalls::crypto::FilecoinKernel) trait so
+/// embeddings that don't run the Filecoin network aren't forced to implement
+/// it.
+pub trait Kernel {
+    /// Verifies that a signature is valid for an address and plaintext.
+    fn verify_signature(
+        &self,
+        signature: &Signature,
+        signer: &Address,
+        plaintext: &[u8],
+    ) -> Result<bool, ExecutionError>;
+
+    /// Hashes input data using blake2b with 256 bit output.
+    fn hash_blake2b(&self, data: &[u8]) -> Result<[u8; 32], ExecutionError>;
+
+    /// Recovers the uncompressed secp256k1 public key for a signature over a
+    /// message hash, mirroring EVM `ecrecover` semantics. Any failure along
+    /// the way (an out-of-range recovery id, a malformed or malleable
+    /// high-`s` signature, or a recovery that lands on the point at infinity)
+    /// is folded into a zeroed result rather than an error, since callers
+    /// treat this as a normal "no key" answer.
+    fn recover_secp_public_key(
+        &self,
+        hash: &[u8; 32],
+        signature: &[u8; 65],
+    ) -> Result<[u8; 65], ExecutionError>;
+
+    /// Pushes a value onto the actor's return stack (e.g. the fault found by
+    /// the `verify_consensus_fault` syscall).
+    fn return_push(&self, value: ConsensusFault) -> Result<u32, ExecutionError>;
+}
@@ -2,16 +2,94 @@ use crate::kernel::ExecutionError;
 use crate::Kernel;
 use cid::Cid;
 use fvm_shared::address::Address;
+use fvm_shared::consensus::ConsensusFault;
 use fvm_shared::crypto::signature::Signature;
 use fvm_shared::piece::PieceInfo;
 use fvm_shared::sector::{
     AggregateSealVerifyProofAndInfos, RegisteredSealProof, SealVerifyInfo, WindowPoStVerifyInfo,
 };
+use rayon::prelude::*;
 use std::collections::HashMap;
 use wasmtime::{Caller, Trap};
 
 use super::Context;
 
+/// Filecoin-specific proof verification and sealing operations: sector seal
+/// proofs, window PoSt, aggregated seals, CommD computation, and consensus
+/// fault detection.
+///
+/// These are split out from [`Kernel`] so that embeddings of the FVM that don't
+/// run the Filecoin network can provide a kernel that implements [`Kernel`]
+/// alone, without carrying the sealing/PoSt machinery. The syscalls below are
+/// only wired up for a kernel implementation that also implements
+/// `FilecoinKernel`.
+pub trait FilecoinKernel: Kernel {
+    /// Computes an unsealed sector CID (CommD) from its constituent piece CIDs
+    /// (CommPs) and sizes.
+    fn compute_unsealed_sector_cid(
+        &self,
+        proof_type: RegisteredSealProof,
+        pieces: &[PieceInfo],
+    ) -> Result<Cid, ExecutionError>;
+
+    /// Verifies a sector seal proof.
+    fn verify_seal(&self, vi: &SealVerifyInfo) -> Result<bool, ExecutionError>;
+
+    /// Verifies a window proof of spacetime.
+    fn verify_post(&self, verify_info: &WindowPoStVerifyInfo) -> Result<bool, ExecutionError>;
+
+    /// Verifies that two block headers provide proof of a consensus fault.
+    fn verify_consensus_fault(
+        &self,
+        h1: &[u8],
+        h2: &[u8],
+        extra: &[u8],
+    ) -> Result<Option<ConsensusFault>, ExecutionError>;
+
+    /// Verifies an aggregated batch of sector seal proofs.
+    fn verify_aggregate_seals(
+        &self,
+        agg: &AggregateSealVerifyProofAndInfos,
+    ) -> Result<bool, ExecutionError>;
+
+    /// Verifies a batch of sector seal proofs, fanning the work out to the
+    /// proof backend (e.g. in parallel via rayon).
+    fn batch_verify_seals(
+        &self,
+        vis: &[(&Address, &[SealVerifyInfo])],
+    ) -> Result<HashMap<Address, Vec<bool>>, ExecutionError>;
+}
+
+/// Binds the crypto syscalls available to every embedding, regardless of
+/// whether its kernel runs the Filecoin network.
+pub fn bind_syscalls(linker: &mut wasmtime::Linker<impl Kernel + 'static>) -> anyhow::Result<()> {
+    linker.func_wrap("vm", "verify_signature", verify_signature)?;
+    linker.func_wrap("vm", "recover_secp_public_key", recover_secp_public_key)?;
+    linker.func_wrap("vm", "hash_blake2b", hash_blake2b)?;
+    Ok(())
+}
+
+/// Binds the Filecoin-specific proof syscalls. Only called for an embedding
+/// whose kernel implements [`FilecoinKernel`]; a kernel that implements
+/// [`Kernel`] alone never has these syscalls registered, so a module that
+/// calls them fails to instantiate with an "unknown import" error instead of
+/// reaching kernel code that doesn't exist.
+pub fn bind_filecoin_syscalls(
+    linker: &mut wasmtime::Linker<impl FilecoinKernel + Sync + 'static>,
+) -> anyhow::Result<()> {
+    linker.func_wrap(
+        "vm",
+        "compute_unsealed_sector_cid",
+        compute_unsealed_sector_cid,
+    )?;
+    linker.func_wrap("vm", "verify_seal", verify_seal)?;
+    linker.func_wrap("vm", "verify_post", verify_post)?;
+    linker.func_wrap("vm", "verify_consensus_fault", verify_consensus_fault)?;
+    linker.func_wrap("vm", "verify_aggregate_seals", verify_aggregate_seals)?;
+    linker.func_wrap("vm", "batch_verify_seals", batch_verify_seals)?;
+    Ok(())
+}
+
 /// Verifies that a signature is valid for an address and plaintext.
 fn verify_signature(
     mut caller: Caller<'_, impl Kernel>,
@@ -34,6 +112,42 @@ fn verify_signature(
         .map_err(Trap::from)
 }
 
+/// Recovers the secp256k1 public key that produced a signature over a message hash,
+/// mirroring EVM `ecrecover` semantics.
+///
+/// `sig_off` must point to a 65-byte compact recoverable signature laid out as
+/// `r (32) || s (32) || v (1)`, where `v` is a recovery id in `0..4`. High-`s`
+/// malleable signatures are rejected. On any failure (invalid recovery id, malformed
+/// signature, or a recovery that lands on the point at infinity), the output buffer
+/// is zeroed rather than this trapping. The output buffer must be sized to 65 bytes
+/// and receives the uncompressed public key.
+fn recover_secp_public_key(
+    mut caller: Caller<'_, impl Kernel>,
+    hash_off: u32, // 32-byte digest
+    sig_off: u32,  // 65-byte r||s||v signature
+    obuf_off: u32,
+) -> Result<(), Trap> {
+    const HASH_LEN: usize = 32;
+    const SIG_LEN: usize = 65;
+    const PUBKEY_LEN: usize = 65;
+
+    let (kernel, mut memory) = caller.kernel_and_memory()?;
+    let pubkey = {
+        let hash = memory.try_slice(hash_off, HASH_LEN as u32)?;
+        let sig = memory.try_slice(sig_off, SIG_LEN as u32)?;
+        kernel
+            .recover_secp_public_key(
+                hash.try_into().expect("hash slice is HASH_LEN bytes"),
+                sig.try_into().expect("signature slice is SIG_LEN bytes"),
+            )
+            .map_err(ExecutionError::from)
+            .map_err(Trap::from)?
+    };
+    let mut obuf = memory.try_slice_mut(obuf_off, PUBKEY_LEN as u32)?;
+    obuf.copy_from_slice(&pubkey);
+    Ok(())
+}
+
 /// Hashes input data using blake2b with 256 bit output.
 ///
 /// The output buffer must be sized to 32 bytes.
@@ -59,7 +173,7 @@ fn hash_blake2b(
 /// Computes an unsealed sector CID (CommD) from its constituent piece CIDs
 /// (CommPs) and sizes.
 fn compute_unsealed_sector_cid(
-    mut caller: Caller<'_, impl Kernel>,
+    mut caller: Caller<'_, impl FilecoinKernel>,
     proof_type: i64, // RegisteredSealProof,
     pieces_off: u32, // [PieceInfo]
     pieces_len: u32,
@@ -72,7 +186,7 @@ fn compute_unsealed_sector_cid(
 
 /// Verifies a sector seal proof.
 fn verify_seal(
-    mut caller: Caller<'_, impl Kernel>,
+    mut caller: Caller<'_, impl FilecoinKernel>,
     info_off: u32, // SealVerifyInfo
     info_len: u32,
 ) -> Result<bool, Trap> {
@@ -86,7 +200,7 @@ fn verify_seal(
 
 /// Verifies a window proof of spacetime.
 fn verify_post(
-    mut caller: Caller<'_, impl Kernel>,
+    mut caller: Caller<'_, impl FilecoinKernel>,
     info_off: u32, // WindowPoStVerifyInfo,
     info_len: u32,
 ) -> Result<bool, Trap> {
@@ -111,7 +225,7 @@ fn verify_post(
 ///
 /// This returns
 fn verify_consensus_fault(
-    mut caller: Caller<'_, impl Kernel>,
+    mut caller: Caller<'_, impl FilecoinKernel>,
     h1_off: u32,
     h1_len: u32,
     h2_off: u32,
@@ -143,7 +257,7 @@ fn verify_consensus_fault(
 }
 
 fn verify_aggregate_seals(
-    mut caller: Caller<'_, impl Kernel>,
+    mut caller: Caller<'_, impl FilecoinKernel>,
     agg_off: u32, // AggregateSealVerifyProofAndInfos
     agg_len: u32,
 ) -> Result<bool, Trap> {
@@ -155,9 +269,78 @@ fn verify_aggregate_seals(
         .map_err(Trap::from)
 }
 
+/// Verifies a batch of sector seal proofs, fanning the flattened list of proofs
+/// out to the kernel's proof backend in parallel. Returns one verification result
+/// per input `SealVerifyInfo`, in order, grouped back under their original address.
+///
+/// A proof that fails verification is reported as `false`; this only returns a
+/// `Trap` if the kernel itself raised an `ExecutionError` while verifying a proof.
+///
+/// If the same address appears in more than one `vis` entry, its groups are
+/// concatenated in the order they appear.
 fn batch_verify_seals(
-    caller: Caller<'_, impl Kernel>,
+    caller: Caller<'_, impl FilecoinKernel + Sync>,
     vis: &[(&Address, &[SealVerifyInfo])],
 ) -> Result<HashMap<Address, Vec<bool>>, Trap> {
-    todo!()
+    let kernel = caller.data();
+
+    // Flatten the per-address seal infos into a single list so rayon can balance
+    // verification work across all proofs at once; the per-address grouping is
+    // reconstructed below from the (known, fixed) group lengths in `vis`, rather
+    // than by keying off the address as results come back.
+    let flattened: Vec<&SealVerifyInfo> = vis.iter().flat_map(|(_, infos)| infos.iter()).collect();
+
+    let flat_results: Vec<bool> = flattened
+        .into_par_iter()
+        .map(|info| kernel.verify_seal(info))
+        .collect::<Result<_, ExecutionError>>()
+        .map_err(Trap::from)?;
+
+    let groups = vis.iter().map(|(addr, infos)| (*addr, infos.len()));
+    Ok(regroup_by_address(groups, &flat_results))
+}
+
+/// Slices `flat_results` back up into the per-address groups described by
+/// `groups` (each a group's address and its length), concatenating groups
+/// that share an address in the order they appear.
+fn regroup_by_address<'a>(
+    groups: impl Iterator<Item = (&'a Address, usize)>,
+    flat_results: &[bool],
+) -> HashMap<Address, Vec<bool>> {
+    let mut out: HashMap<Address, Vec<bool>> = HashMap::new();
+    let mut pos = 0;
+    for (addr, len) in groups {
+        let group = &flat_results[pos..pos + len];
+        pos += len;
+        out.entry(*addr).or_insert_with(Vec::new).extend_from_slice(group);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regroup_concatenates_duplicate_addresses_in_order() {
+        let a = Address::new_id(100);
+        let b = Address::new_id(101);
+        let groups = vec![(&a, 2), (&b, 1), (&a, 1)];
+        let flat_results = [true, false, true, false];
+
+        let out = regroup_by_address(groups.into_iter(), &flat_results);
+
+        assert_eq!(out.get(&a), Some(&vec![true, false, false]));
+        assert_eq!(out.get(&b), Some(&vec![true]));
+    }
+
+    #[test]
+    fn regroup_handles_an_empty_group() {
+        let a = Address::new_id(100);
+        let groups = vec![(&a, 0)];
+
+        let out = regroup_by_address(groups.into_iter(), &[]);
+
+        assert_eq!(out.get(&a), Some(&vec![]));
+    }
 }